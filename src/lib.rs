@@ -2,7 +2,7 @@
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Data, DataStruct, DeriveInput, Field, Fields, Lit, Member, Meta, Result};
+use syn::{Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Lit, Member, Meta, Result};
 
 /// Creates a `new()` constructor with specified default values for a struct.
 ///
@@ -61,6 +61,22 @@ use syn::{Data, DataStruct, DeriveInput, Field, Fields, Lit, Member, Meta, Resul
 /// assert_eq!(model.1, 123);
 /// ```
 ///
+/// `#[new(into)]` works like `#[new]`, but the constructor accepts `impl Into<FieldType>`
+/// and converts it, so callers can pass anything that converts into the field's type.
+///
+/// ```rust
+/// # use defew::Defew;
+/// #
+/// #[derive(Defew)]
+/// struct Data {
+///     #[new(into)]
+///     name: String,
+/// }
+///
+/// let model = Data::new("literal");
+/// assert_eq!(model.name, "literal".to_string());
+/// ```
+///
 /// ## With Generics
 ///
 /// ```rust
@@ -114,6 +130,36 @@ use syn::{Data, DataStruct, DeriveInput, Field, Fields, Lit, Member, Meta, Resul
 /// assert_eq!(value.a, 42);
 /// ```
 ///
+/// ## With Enums
+///
+/// `#[derive(Defew)]` also works on enums. A constructor is generated for each variant,
+/// named `new` when the enum has a single variant and `new_<variant>` (in snake_case)
+/// otherwise. Each variant's fields support the same `#[new]` attributes as a struct.
+///
+/// ```rust
+/// # use defew::Defew;
+/// #
+/// #[derive(Defew)]
+/// enum Data {
+///     Foo,
+///     Bar(#[new] i32, #[new(42)] u64),
+///     Baz {
+///         #[new]
+///         a: i32,
+///         #[new(a * 2)]
+///         b: i32,
+///     },
+/// }
+///
+/// let foo = Data::new_foo();
+/// let bar = Data::new_bar(1);
+/// let baz = Data::new_baz(3);
+///
+/// assert!(matches!(foo, Data::Foo));
+/// assert!(matches!(bar, Data::Bar(1, 42)));
+/// assert!(matches!(baz, Data::Baz { a: 3, b: 6 }));
+/// ```
+///
 /// ## Using other fields
 ///
 /// ```rust
@@ -137,20 +183,56 @@ use syn::{Data, DataStruct, DeriveInput, Field, Fields, Lit, Member, Meta, Resul
 /// assert_eq!(value.2, 118.0);
 /// ```
 ///
-/// # Errors
+/// ## Fallible constructors
 ///
-/// compile fails if #[derive(Defew)] is used on anything other than a struct.
+/// `#[defew(try)]` makes `new()` return `Result<Self, E>`, so field initializers may use the
+/// `?` operator. The error type defaults to `Box<dyn std::error::Error>`, or can be pinned with
+/// `#[defew(try = ErrorType)]`.
 ///
-/// ```compile_fail
+/// ```rust
 /// # use defew::Defew;
 /// #
 /// #[derive(Defew)]
-/// enum Data {
-///     Foo,
-///     Bar,
+/// #[defew(try)]
+/// struct Data {
+///     #[new("123".parse()?)]
+///     a: i32,
+/// }
+///
+/// let value = Data::new().unwrap();
+/// assert_eq!(value.a, 123);
+/// ```
+///
+/// ## Multiple constructors
+///
+/// A repeated `#[defew(fn name)]` attribute declares an additional constructor alongside `new`.
+/// A field can override its value for one of those constructors with
+/// `#[new(for = "name", value = ..)]`; fields without an override keep their base `#[new]`
+/// behavior.
+///
+/// ```rust
+/// # use defew::Defew;
+/// #
+/// #[derive(Defew)]
+/// #[defew(fn with_defaults)]
+/// struct Data {
+///     #[new]
+///     a: i32,
+///     #[new(for = "with_defaults", value = 99)]
+///     b: i32,
 /// }
+///
+/// let value = Data::new(1);
+/// assert_eq!(value.a, 1);
+/// assert_eq!(value.b, 0);
+///
+/// let value = Data::with_defaults(1);
+/// assert_eq!(value.a, 1);
+/// assert_eq!(value.b, 99);
 /// ```
 ///
+/// # Errors
+///
 /// compile fails if #[derive(Defew)] is used on a unit struct.
 ///
 /// ```compile_fail
@@ -219,28 +301,101 @@ macro_rules! err {
 }
 
 fn defew_internal(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
-    let Data::Struct(DataStruct { fields, .. }) = &input.data else {
-        err!("Defew only supports structs");
+    // #[defew(fn with_defaults)] attributes declare extra named constructors; they may be
+    // repeated, so they are pulled out before the single "mode" attribute is looked up.
+    let mode_attrs: Vec<syn::Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| !(attr.path().is_ident("defew") && is_fn_decl(&attr.meta)))
+        .cloned()
+        .collect();
+    let ctor_names = collect_ctor_names(&input.attrs)?;
+
+    let defew_meta = find_meta(&mode_attrs, "defew")?;
+    // If the attribute is #[defew(try)] or #[defew(try = ErrorType)], `new` returns a `Result`
+    // instead of `Self`, and field initializers may use the `?` operator.
+    let try_mode = match defew_meta {
+        Some(match_token!(MetaList, tr)) => parse_try_mode(tr),
+        _ => None,
+    };
+
+    let (trait_for, visibility) = if try_mode.is_some() {
+        (quote!(), quote!(pub))
+    } else {
+        match defew_meta {
+            // If the attribute is #[defew(trait)], we will implement the trait
+            Some(match_token!(MetaList, tr)) if !tr.is_empty() => (quote! { #tr for }, quote!()), // => `impl Trait for Struct`, `fn new(..)`
+            // If the attribute is #[defew], we will implement the new() constructor with private visibility
+            Some(Meta::Path(_)) => (quote!(), quote!()), // => `impl Struct`, `fn new(..)`
+            // If the attribute is #[defew = "crate"], we will implement the new() constructor with specified visibility
+            Some(match_token!(NameValue, Lit::Str(s))) => {
+                let restriction: proc_macro2::TokenStream = s.parse()?;
+                (quote!(), quote!(pub(#restriction))) // => `impl Struct`, `pub(crate) fn new(..)`
+            }
+            // If the attribute is not present, we will not implement any trait
+            None => (quote!(), quote!(pub)), // => `impl Struct`, `pub fn new(..)`
+            Some(meta) => err!(meta, "Defew does not support this syntax"),
+        }
+    };
+
+    let functions = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            if matches!(fields, Fields::Unit) {
+                err!("Defew does not support unit structs");
+            }
+            validate_field_overrides(fields, &ctor_names)?;
+            if !ctor_names.is_empty() && !trait_for.is_empty() {
+                err!("Defew does not support #[defew(fn ..)] together with a trait target");
+            }
+
+            let mut fn_names = vec![format_ident!("new")];
+            fn_names.extend(ctor_names.iter().cloned());
+            fn_names
+                .iter()
+                .map(|fn_name| struct_constructor(fields, fn_name, &visibility, try_mode.clone()))
+                .collect::<Result<Vec<_>>>()?
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            if try_mode.is_some() {
+                err!("Defew does not support #[defew(try)] on enums");
+            }
+            if !ctor_names.is_empty() {
+                err!("Defew does not support #[defew(fn ..)] on enums");
+            }
+            if !trait_for.is_empty() {
+                err!("Defew does not support a trait target on enums");
+            }
+            variants
+                .iter()
+                .map(|variant| {
+                    validate_field_overrides(&variant.fields, &[])?;
+                    variant_constructor(variant, variants.len() == 1, &visibility)
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        Data::Union(_) => err!("Defew does not support unions"),
     };
-    if matches!(fields, Fields::Unit) {
-        err!("Defew does not support unit structs");
-    }
-
-    let (trait_for, visibility) = match find_meta(&input.attrs, "defew")? {
-        // If the attribute is #[defew(trait)], we will implement the trait
-        Some(match_token!(MetaList, tr)) if !tr.is_empty() => (quote! { #tr for }, quote!()), // => `impl Trait for Struct`, `fn new(..)`
-        // If the attribute is #[defew], we will implement the new() constructor with private visibility
-        Some(Meta::Path(_)) => (quote!(), quote!()), // => `impl Struct`, `fn new(..)`
-        // If the attribute is #[defew = "crate"], we will implement the new() constructor with specified visibility
-        Some(match_token!(NameValue, Lit::Str(s))) => {
-            let restriction: proc_macro2::TokenStream = s.parse()?;
-            (quote!(), quote!(pub(#restriction))) // => `impl Struct`, `pub(crate) fn new(..)`
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_for #struct_name #ty_generics #where_clause {
+            #(#functions)*
         }
-        // If the attribute is not present, we will not implement any trait
-        None => (quote!(), quote!(pub)), // => `impl Struct`, `pub fn new(..)`
-        Some(meta) => err!(meta, "Defew does not support this syntax"),
     };
+    Ok(expanded)
+}
 
+/// Collects the constructor parameters and the `let`/`const` variable bindings for a set of fields,
+/// honoring each field's `#[new]` / `#[new(expr)]` / `#[new = const]` attribute. `ctor` is the
+/// name of the constructor being built; fields with a `#[new(for = "ctor", value = ..)]`
+/// override for it use that value instead of their base attribute.
+fn field_ctor_parts(
+    fields: &Fields,
+    ctor: &str,
+) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>, Vec<syn::Ident>)> {
     let names: Vec<_> = fields
         .members()
         .map(|member| match member {
@@ -253,9 +408,21 @@ fn defew_internal(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
     let mut params = Vec::new(); // params for the `::new(..)` constructor
     let mut variables = Vec::new();
     for (Field { ty, attrs, .. }, name) in fields.iter().zip(&names) {
-        match find_meta(attrs, "new")? {
+        if let Some(value) = field_override_value(attrs, ctor)? {
+            variables.push(quote! { let #name: #ty = #value; });
+            continue;
+        }
+
+        let base_attrs = base_new_attrs(attrs);
+        match find_meta(&base_attrs, "new")? {
             // If the attribute is #[new], we will ask for the value at runtime
             Some(Meta::Path(_)) => params.push(quote! ( #name: #ty )),
+            // If the attribute is #[new(into)], we will ask for a value convertible into the
+            // field type and convert it at runtime
+            Some(match_token!(MetaList, v)) if is_into_marker(v) => {
+                params.push(quote! ( #name: impl ::core::convert::Into<#ty> ));
+                variables.push(quote! { let #name: #ty = #name.into(); });
+            }
             // If the attribute is #[new(value)], we will use the provided value
             Some(match_token!(MetaList, v)) => variables.push(quote! { let #name: #ty = #v; }),
             // If the attribute is #[new = value], we will use the provided value as const
@@ -266,22 +433,267 @@ fn defew_internal(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
         }
     }
 
-    let struct_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
+    Ok((params, variables, names))
+}
+
+/// Builds a doc comment for a generated constructor: the default `new` keeps `base` verbatim,
+/// alternate named constructors (declared via `#[defew(fn ..)]`) append their own name to it.
+fn ctor_doc(fn_name: &syn::Ident, base: &str) -> String {
+    if fn_name == "new" {
+        base.to_string()
+    } else {
+        format!("{base} (`{fn_name}` constructor)")
+    }
+}
+
+fn struct_constructor(
+    fields: &Fields,
+    fn_name: &syn::Ident,
+    visibility: &proc_macro2::TokenStream,
+    try_mode: Option<Option<syn::Type>>,
+) -> Result<proc_macro2::TokenStream> {
+    let (params, variables, names) = field_ctor_parts(fields, &fn_name.to_string())?;
     let field_values = fields.members().zip(names).map(|(f, v)| quote! { #f: #v });
 
-    let expanded = quote! {
-        #[automatically_derived]
-        impl #impl_generics #trait_for #struct_name #ty_generics #where_clause {
-            #[doc = "Creates a new instance of the struct with default values"]
+    let Some(err_ty) = try_mode else {
+        let doc = ctor_doc(fn_name, "Creates a new instance of the struct with default values");
+        return Ok(quote! {
+            #[doc = #doc]
             #[allow(non_upper_case_globals)]
-            #visibility fn new(#(#params),*) -> Self {
+            #visibility fn #fn_name(#(#params),*) -> Self {
                 #(#variables)*
                 Self { #(#field_values),* }
             }
+        });
+    };
+
+    // Without a user-named error type, fall back to a boxed error trait object: `?` converts any
+    // `std::error::Error` into it via std's blanket `From` impl, with no bound on the fields here.
+    let err_ty = match err_ty {
+        Some(ty) => quote! { #ty },
+        None => quote! { ::std::boxed::Box<dyn ::std::error::Error> },
+    };
+    let doc = ctor_doc(
+        fn_name,
+        "Creates a new instance of the struct with default values, or an error if a field initializer fails",
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(non_upper_case_globals)]
+        #visibility fn #fn_name(#(#params),*) -> ::core::result::Result<Self, #err_ty> {
+            #(#variables)*
+            ::core::result::Result::Ok(Self { #(#field_values),* })
         }
+    })
+}
+
+fn variant_constructor(
+    variant: &syn::Variant,
+    is_only_variant: bool,
+    visibility: &proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream> {
+    let variant_name = &variant.ident;
+    let fn_name = if is_only_variant {
+        format_ident!("new")
+    } else {
+        format_ident!("new_{}", to_snake_case(&variant_name.to_string()))
     };
-    Ok(expanded)
+    let doc = format!("Creates a new instance of the `{variant_name}` variant with default values");
+
+    let (params, variables, names) = field_ctor_parts(&variant.fields, "new")?;
+    let construct = match &variant.fields {
+        Fields::Unit => quote! { Self::#variant_name },
+        Fields::Unnamed(_) => quote! { Self::#variant_name(#(#names),*) },
+        Fields::Named(_) => {
+            let field_values = variant
+                .fields
+                .members()
+                .zip(names)
+                .map(|(f, v)| quote! { #f: #v });
+            quote! { Self::#variant_name { #(#field_values),* } }
+        }
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(non_upper_case_globals)]
+        #visibility fn #fn_name(#(#params),*) -> Self {
+            #(#variables)*
+            #construct
+        }
+    })
+}
+
+/// Converts a `PascalCase` variant name into `snake_case` for use in a constructor name.
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Checks whether `#[new(..)]`'s inner tokens are the bare `into` marker rather than a value
+/// expression.
+fn is_into_marker(tokens: &proc_macro2::TokenStream) -> bool {
+    syn::parse2::<syn::Ident>(tokens.clone()).is_ok_and(|ident| ident == "into")
+}
+
+/// Parses `#[defew(try)]`'s inner tokens as the `try` marker, optionally followed by
+/// `= ErrorType`. Returns `None` when the tokens are not the `try` marker at all (e.g. a trait
+/// name), `Some(None)` for a bare `try`, and `Some(Some(ty))` for `try = ty`.
+fn parse_try_mode(tokens: &proc_macro2::TokenStream) -> Option<Option<syn::Type>> {
+    syn::parse::Parser::parse2(
+        |input: syn::parse::ParseStream| -> Result<Option<syn::Type>> {
+            use syn::ext::IdentExt;
+            let ident = input.call(syn::Ident::parse_any)?;
+            if ident != "try" {
+                err!(ident, "not a try marker");
+            }
+            if input.is_empty() {
+                return Ok(None);
+            }
+            input.parse::<syn::Token![=]>()?;
+            Ok(Some(input.parse()?))
+        },
+        tokens.clone(),
+    )
+    .ok()
+}
+
+/// Checks whether `#[defew(..)]`'s inner tokens declare an alternate constructor, i.e. `fn name`.
+fn is_fn_decl(meta: &Meta) -> bool {
+    matches!(meta, match_token!(MetaList, tr) if parse_fn_decl(tr).is_some())
+}
+
+/// Parses `#[defew(fn with_defaults)]`'s inner tokens into the declared constructor name.
+fn parse_fn_decl(tokens: &proc_macro2::TokenStream) -> Option<syn::Ident> {
+    syn::parse::Parser::parse2(
+        |input: syn::parse::ParseStream| -> Result<syn::Ident> {
+            input.parse::<syn::Token![fn]>()?;
+            input.parse()
+        },
+        tokens.clone(),
+    )
+    .ok()
+}
+
+/// Collects the names of every alternate constructor declared via a repeated
+/// `#[defew(fn name)]` attribute, erroring on a duplicate name or a name of `new`.
+fn collect_ctor_names(attrs: &[syn::Attribute]) -> Result<Vec<syn::Ident>> {
+    let mut names = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("defew")) {
+        let match_token!(MetaList, tr) = &attr.meta else {
+            continue;
+        };
+        let Some(name) = parse_fn_decl(tr) else {
+            continue;
+        };
+        if name == "new" {
+            err!(name, "Defew already generates a `new` constructor");
+        }
+        if names.contains(&name) {
+            err!(&name, format!("Defew accepts one #[defew(fn {name})] attribute"));
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
+/// Checks whether `#[new(..)]`'s inner tokens are a `for = "ctor", value = ..` override rather
+/// than a base value expression.
+fn is_for_override(tokens: &proc_macro2::TokenStream) -> bool {
+    matches!(
+        tokens.clone().into_iter().next(),
+        Some(proc_macro2::TokenTree::Ident(ident)) if ident == "for"
+    )
+}
+
+/// A parsed `#[new(for = "ctor", value = ..)]` override.
+struct ForOverride {
+    ctor: syn::LitStr,
+    value: syn::Expr,
+}
+
+impl syn::parse::Parse for ForOverride {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        use syn::ext::IdentExt;
+        input.call(syn::Ident::parse_any)?; // "for", already checked by `is_for_override`
+        input.parse::<syn::Token![=]>()?;
+        let ctor = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let key: syn::Ident = input.parse()?;
+        if key != "value" {
+            err!(key, "Defew only supports `value` here");
+        }
+        input.parse::<syn::Token![=]>()?;
+        let value = input.parse()?;
+        Ok(ForOverride { ctor, value })
+    }
+}
+
+/// Returns the field's attributes with any `#[new(for = .., value = ..)]` overrides removed, so
+/// the remaining base `#[new]` attribute can be looked up with [`find_meta`] as usual.
+fn base_new_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            !(attr.path().is_ident("new")
+                && matches!(&attr.meta, match_token!(MetaList, tr) if is_for_override(tr)))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Looks up the `#[new(for = "ctor", value = ..)]` override targeting `ctor`, if any.
+fn field_override_value(attrs: &[syn::Attribute], ctor: &str) -> Result<Option<syn::Expr>> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("new")) {
+        let match_token!(MetaList, tr) = &attr.meta else {
+            continue;
+        };
+        if !is_for_override(tr) {
+            continue;
+        }
+        let over: ForOverride = syn::parse2(tr.clone())?;
+        if over.ctor.value() == ctor {
+            return Ok(Some(over.value));
+        }
+    }
+    Ok(None)
+}
+
+/// Validates every field's `#[new(for = "ctor", value = ..)]` overrides against the struct's
+/// declared constructors, catching an unknown target or a field with two overrides for the
+/// same constructor before constructor generation begins.
+fn validate_field_overrides(fields: &Fields, ctor_names: &[syn::Ident]) -> Result<()> {
+    for field in fields.iter() {
+        let mut seen = std::collections::HashSet::new();
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("new")) {
+            let match_token!(MetaList, tr) = &attr.meta else {
+                continue;
+            };
+            if !is_for_override(tr) {
+                continue;
+            }
+            let over: ForOverride = syn::parse2(tr.clone())?;
+            let name = over.ctor.value();
+            if !ctor_names.iter().any(|ctor| ctor == &name) {
+                err!(over.ctor, format!("Defew has no constructor named `{name}`"));
+            }
+            if !seen.insert(name.clone()) {
+                err!(over.ctor, format!("Defew accepts one override for constructor `{name}` per field"));
+            }
+        }
+    }
+    Ok(())
 }
 
 fn find_meta<'a>(attrs: &'a [syn::Attribute], name: &'static str) -> Result<Option<&'a syn::Meta>> {
@@ -489,6 +901,220 @@ mod tests {
         assert_eq!(defew_internal(&input).unwrap().to_string(), output);
     }
 
+    #[test]
+    fn test_defew_internal_with_into() {
+        let input = parse_quote! {
+            struct Data {
+                #[new(into)]
+                name: String,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the struct with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new(name: impl ::core::convert::Into<String>) -> Self {
+                    let name: String = name.into();
+                    Self { name: name }
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_try() {
+        let input = parse_quote! {
+            #[defew(try)]
+            struct Data {
+                #[new("123".parse()?)]
+                a: i32,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the struct with default values, or an error if a field initializer fails"]
+                #[allow(non_upper_case_globals)]
+                pub fn new() -> ::core::result::Result<Self, ::std::boxed::Box<dyn ::std::error::Error> > {
+                    let a: i32 = "123".parse()?;
+                    ::core::result::Result::Ok(Self { a: a })
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_try_error_type() {
+        let input = parse_quote! {
+            #[defew(try = std::num::ParseIntError)]
+            struct Data {
+                #[new("123".parse()?)]
+                a: i32,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the struct with default values, or an error if a field initializer fails"]
+                #[allow(non_upper_case_globals)]
+                pub fn new() -> ::core::result::Result<Self, std::num::ParseIntError> {
+                    let a: i32 = "123".parse()?;
+                    ::core::result::Result::Ok(Self { a: a })
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_try_on_enum() {
+        let input = parse_quote! {
+            #[defew(try)]
+            enum Data {
+                Foo,
+            }
+        };
+
+        let output = "Defew does not support #[defew(try)] on enums";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_alternate_constructor() {
+        let input = parse_quote! {
+            #[defew(fn with_defaults)]
+            struct Data {
+                #[new]
+                a: i32,
+                #[new(for = "with_defaults", value = 99)]
+                b: i32,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the struct with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new(a: i32) -> Self {
+                    let b: i32 = ::core::default::Default::default();
+                    Self { a: a, b: b }
+                }
+                #[doc = "Creates a new instance of the struct with default values (`with_defaults` constructor)"]
+                #[allow(non_upper_case_globals)]
+                pub fn with_defaults(a: i32) -> Self {
+                    let b: i32 = 99;
+                    Self { a: a, b: b }
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_unknown_constructor_override() {
+        let input = parse_quote! {
+            struct Data {
+                #[new(for = "with_defaults", value = 99)]
+                a: i32,
+            }
+        };
+
+        let output = "Defew has no constructor named `with_defaults`";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_duplicate_constructor_declaration() {
+        let input = parse_quote! {
+            #[defew(fn with_defaults)]
+            #[defew(fn with_defaults)]
+            struct Data {
+                a: i32,
+            }
+        };
+
+        let output = "Defew accepts one #[defew(fn with_defaults)] attribute";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_constructor_on_trait() {
+        let input = parse_quote! {
+            #[defew(DataTrait)]
+            #[defew(fn with_defaults)]
+            struct Data {
+                a: i32,
+            }
+        };
+
+        let output = "Defew does not support #[defew(fn ..)] together with a trait target";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_unknown_constructor_override_on_enum() {
+        let input = parse_quote! {
+            enum Data {
+                Foo {
+                    #[new(for = "with_defaults", value = 99)]
+                    a: i32,
+                },
+            }
+        };
+
+        let output = "Defew has no constructor named `with_defaults`";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_trait_on_enum() {
+        let input = parse_quote! {
+            #[defew(DataTrait)]
+            enum Data {
+                Foo,
+                Bar,
+            }
+        };
+
+        let output = "Defew does not support a trait target on enums";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_constructor_on_enum() {
+        let input = parse_quote! {
+            #[defew(fn with_defaults)]
+            enum Data {
+                Foo,
+            }
+        };
+
+        let output = "Defew does not support #[defew(fn ..)] on enums";
+
+        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+    }
+
     #[test]
     fn test_defew_internal_with_unit_struct() {
         let input = parse_quote! {
@@ -509,9 +1135,83 @@ mod tests {
             }
         };
 
-        let output = "Defew only supports structs";
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the `Foo` variant with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new_foo() -> Self {
+                    Self::Foo
+                }
+                #[doc = "Creates a new instance of the `Bar` variant with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new_bar() -> Self {
+                    Self::Bar
+                }
+            }
+        }
+        .to_string();
 
-        assert_eq!(defew_internal(&input).unwrap_err().to_string(), output);
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_enum_single_variant() {
+        let input = parse_quote! {
+            enum Data {
+                Foo {
+                    #[new]
+                    a: i32,
+                    #[new(a * 2)]
+                    b: i32,
+                },
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the `Foo` variant with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new(a: i32) -> Self {
+                    let b: i32 = a * 2;
+                    Self::Foo { a: a, b: b }
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
+    }
+
+    #[test]
+    fn test_defew_internal_with_enum_unnamed_variant() {
+        let input = parse_quote! {
+            enum Data {
+                Foo(#[new] i32, #[new(42)] u64),
+                Bar,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl Data {
+                #[doc = "Creates a new instance of the `Foo` variant with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new_foo(_0: i32) -> Self {
+                    let _1: u64 = 42;
+                    Self::Foo(_0, _1)
+                }
+                #[doc = "Creates a new instance of the `Bar` variant with default values"]
+                #[allow(non_upper_case_globals)]
+                pub fn new_bar() -> Self {
+                    Self::Bar
+                }
+            }
+        }
+        .to_string();
+
+        assert_eq!(defew_internal(&input).unwrap().to_string(), output);
     }
 
     #[test]
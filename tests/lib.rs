@@ -145,6 +145,86 @@ mod tests {
         assert_eq!(model.a2.value, 42);
     }
 
+    #[test]
+    fn test_defew_enum() {
+        #[derive(Defew)]
+        enum Data {
+            Foo,
+            Bar(#[new] i32, #[new(42)] u64),
+            Baz {
+                #[new]
+                a: i32,
+                #[new(a * 2)]
+                b: i32,
+            },
+        }
+
+        assert!(matches!(Data::new_foo(), Data::Foo));
+        assert!(matches!(Data::new_bar(1), Data::Bar(1, 42)));
+        assert!(matches!(Data::new_baz(3), Data::Baz { a: 3, b: 6 }));
+    }
+
+    #[test]
+    fn test_defew_into() {
+        #[derive(Defew)]
+        struct Data {
+            #[new(into)]
+            name: String,
+            #[new(name.len() as u64)]
+            name_len: u64,
+        }
+
+        let model = Data::new("literal");
+        assert_eq!(model.name, "literal".to_string());
+        assert_eq!(model.name_len, 7);
+    }
+
+    #[test]
+    fn test_defew_try() {
+        #[derive(Defew)]
+        #[defew(try)]
+        struct Data {
+            #[new("123".parse()?)]
+            a: i32,
+            #[new(42)]
+            b: u64,
+        }
+
+        let model = Data::new().unwrap();
+        assert_eq!(model.a, 123);
+        assert_eq!(model.b, 42);
+
+        #[derive(Defew)]
+        #[defew(try)]
+        #[allow(dead_code)]
+        struct Invalid {
+            #[new("abc".parse()?)]
+            a: i32,
+        }
+
+        assert!(Invalid::new().is_err());
+    }
+
+    #[test]
+    fn test_defew_alternate_constructor() {
+        #[derive(Defew)]
+        #[defew(fn with_defaults)]
+        struct Data {
+            #[new]
+            a: i32,
+            #[new(for = "with_defaults", value = 99)]
+            b: i32,
+        }
+
+        let model = Data::new(1);
+        assert_eq!(model.a, 1);
+        assert_eq!(model.b, 0);
+
+        let model = Data::with_defaults(1);
+        assert_eq!(model.a, 1);
+        assert_eq!(model.b, 99);
+    }
+
     #[test]
     fn test_defew_with_trait() {
         trait NewTrait {